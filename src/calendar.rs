@@ -0,0 +1,92 @@
+// Copyright 2015 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use Error;
+use time;
+
+pub fn days_in_month(year: u64, month: u64) -> u64 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => if is_leap_year(year) { 29 } else { 28 },
+        _ => 31, // unreachable in practice; callers validate `month` first.
+    }
+}
+
+fn is_leap_year(year: u64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+}
+
+// The number of days from the Unix epoch (1970-01-01) to `year-month-day`,
+// using the civil-to-days half of Howard Hinnant's "chrono-Compatible
+// Low-Level Date Algorithms"
+// (http://howardhinnant.github.io/date_algorithms.html).
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y / 400;
+    let yoe = y - era * 400;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+// The inverse of `days_from_civil`.
+fn civil_from_days(days_since_epoch: u64) -> (u64, u64, u64) {
+    let z = days_since_epoch + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = if month <= 2 { y + 1 } else { y };
+    (year, month, day)
+}
+
+pub fn time_from_ymdhms_utc(year: u64, month: u64, day_of_month: u64, hours: u64,
+                            minutes: u64, seconds: u64)
+                            -> Result<time::Time, Error> {
+    let days = days_from_civil(year, month, day_of_month);
+    let seconds_of_day = (hours * 60 + minutes) * 60 + seconds;
+    let total_seconds = (days as i64)
+        .checked_mul(86_400)
+        .and_then(|s| s.checked_add(seconds_of_day as i64))
+        .ok_or(Error::BadDERTime)?;
+    if total_seconds < 0 {
+        return Err(Error::BadDERTime);
+    }
+    Ok(time::Time::from_seconds_since_unix_epoch(total_seconds as u64))
+}
+
+/// Recovers the UTC year/month/day/hour/minute/second fields from a
+/// `time::Time`, the inverse of `time_from_ymdhms_utc`. Used by
+/// `der::format_time` to render a previously-parsed `Time` back to a
+/// string.
+#[cfg(feature = "std")]
+pub fn ymdhms_from_time(t: &time::Time)
+                        -> Result<(u64, u64, u64, u64, u64, u64), Error> {
+    let total_seconds = t.as_seconds_since_unix_epoch();
+    let days_since_epoch = total_seconds / 86_400;
+    let seconds_of_day = total_seconds % 86_400;
+
+    let (year, month, day) = civil_from_days(days_since_epoch);
+    let hour = seconds_of_day / 3600;
+    let minute = (seconds_of_day % 3600) / 60;
+    let second = seconds_of_day % 60;
+
+    Ok((year, month, day, hour, minute, second))
+}
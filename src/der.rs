@@ -111,6 +111,20 @@ pub fn small_nonnegative_integer<'a>(input: &'a mut untrusted::Reader)
 
 pub fn time_choice<'a>(input: &mut untrusted::Reader<'a>)
                        -> Result<time::Time, Error> {
+    time_choice_general(input, false)
+}
+
+// As `time_choice`, but additionally accepts the nonconformant (w.r.t.
+// RFC 5280) `+hhmm`/`-hhmm` zone suffix and a fractional-seconds field, as
+// produced by some real-world CAs. This is kept separate from `time_choice`
+// so that strict RFC 5280 conformance remains the default.
+pub fn time_choice_lenient<'a>(input: &mut untrusted::Reader<'a>)
+                               -> Result<time::Time, Error> {
+    time_choice_general(input, true)
+}
+
+fn time_choice_general<'a>(input: &mut untrusted::Reader<'a>, lenient: bool)
+                           -> Result<time::Time, Error> {
     let is_utc_time = input.peek(Tag::UTCTime as u8);
     let expected_tag = if is_utc_time { Tag::UTCTime }
                        else { Tag::GeneralizedTime };
@@ -134,6 +148,50 @@ pub fn time_choice<'a>(input: &mut untrusted::Reader<'a>)
         Ok(value)
     }
 
+    fn peek_digit(inner: &untrusted::Reader) -> bool {
+        (b'0'..=b'9').any(|digit| inner.peek(digit))
+    }
+
+    // Consumes a `.` followed by one or more digits, if present. The
+    // fractional value itself is not retained; `time::Time` has no
+    // sub-second resolution, so the whole-seconds value already read is
+    // used as-is. A `.` with no digit after it is `Error::BadDERTime`.
+    fn skip_fractional_seconds(inner: &mut untrusted::Reader)
+                               -> Result<(), Error> {
+        if !inner.peek(b'.') {
+            return Ok(());
+        }
+        let _ = inner.read_byte();
+        if !peek_digit(inner) {
+            return Err(Error::BadDERTime);
+        }
+        while peek_digit(inner) {
+            let _ = inner.read_byte();
+        }
+        Ok(())
+    }
+
+    // Reads a `+hhmm`/`-hhmm` zone offset and returns it as signed seconds
+    // east of UTC.
+    fn read_zone_offset_seconds(inner: &mut untrusted::Reader, sign: i64)
+                                -> Result<i64, Error> {
+        let hours = read_two_digits(inner, 0, 23)?;
+        let minutes = read_two_digits(inner, 0, 59)?;
+        Ok(sign * ((hours as i64) * 3600 + (minutes as i64) * 60))
+    }
+
+    // Subtracts `offset_seconds` (east of UTC) from `unadjusted`, returning
+    // the `Time` this local civil time represents in UTC. Returns
+    // `Error::BadDERTime` if the adjustment would underflow the epoch.
+    fn apply_offset_seconds(unadjusted: time::Time, offset_seconds: i64)
+                            -> Result<time::Time, Error> {
+        let total_seconds = unadjusted.as_seconds_since_unix_epoch() as i64 - offset_seconds;
+        if total_seconds < 0 {
+            return Err(Error::BadDERTime);
+        }
+        Ok(time::Time::from_seconds_since_unix_epoch(total_seconds as u64))
+    }
+
     nested(input, expected_tag, Error::BadDER, |value| {
         let (year_hi, year_lo) =
             if is_utc_time {
@@ -154,153 +212,500 @@ pub fn time_choice<'a>(input: &mut untrusted::Reader<'a>)
         let minutes = read_two_digits(value, 0, 59)?;
         let seconds = read_two_digits(value, 0, 59)?;
 
-        let time_zone = value.read_byte().map_err(|_| Error::BadDERTime)?;
-        if time_zone != b'Z' {
-            return Err(Error::BadDERTime);
+        if lenient {
+            skip_fractional_seconds(value)?;
         }
 
-        calendar::time_from_ymdhms_utc(year, month, day_of_month, hours, minutes,
-                                       seconds)
+        let time_zone = value.read_byte().map_err(|_| Error::BadDERTime)?;
+        let offset_seconds = match time_zone {
+            b'Z' => 0,
+            b'+' if lenient => read_zone_offset_seconds(value, 1)?,
+            b'-' if lenient => read_zone_offset_seconds(value, -1)?,
+            _ => return Err(Error::BadDERTime),
+        };
+
+        let unadjusted = calendar::time_from_ymdhms_utc(year, month, day_of_month,
+                                                        hours, minutes, seconds)?;
+        if offset_seconds == 0 {
+            Ok(unadjusted)
+        } else {
+            apply_offset_seconds(unadjusted, offset_seconds)
+        }
     })
 }
 
-///
 #[cfg(feature = "std")]
-pub fn parse_oid<'a>(input: &mut untrusted::Reader<'a>) -> Result<std::string::String, Error> {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Component {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+#[cfg(feature = "std")]
+enum FormatItem {
+    Literal(std::vec::Vec<u8>),
+    Component(Component),
+}
+
+// Parses a `time`-crate-style format description such as
+// `"[year]-[month]-[day]T[hour]:[minute]:[second]Z"` into a sequence of
+// literal and component items. A literal `[` is written as `[[`.
+#[cfg(feature = "std")]
+fn parse_format_description(desc: &str) -> Result<std::vec::Vec<FormatItem>, Error> {
+    use std::vec::Vec;
+
+    let bytes = desc.as_bytes();
+    let mut items = Vec::new();
+    let mut literal = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] != b'[' {
+            literal.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+
+        if bytes.get(i + 1) == Some(&b'[') {
+            literal.push(b'[');
+            i += 2;
+            continue;
+        }
+
+        let close = bytes[i..].iter().position(|&b| b == b']')
+                              .map(|offset| i + offset)
+                              .ok_or(Error::BadDER)?;
+        let component = match &desc[(i + 1)..close] {
+            "year" => Component::Year,
+            "month" => Component::Month,
+            "day" => Component::Day,
+            "hour" => Component::Hour,
+            "minute" => Component::Minute,
+            "second" => Component::Second,
+            _ => return Err(Error::BadDER),
+        };
+
+        if !literal.is_empty() {
+            items.push(FormatItem::Literal(std::mem::replace(&mut literal, Vec::new())));
+        }
+        items.push(FormatItem::Component(component));
+        i = close + 1;
+    }
+
+    if !literal.is_empty() {
+        items.push(FormatItem::Literal(literal));
+    }
+
+    Ok(items)
+}
+
+// Appends `value` to `out`, zero-padded on the left to `width` digits.
+#[cfg(feature = "std")]
+fn push_zero_padded(out: &mut std::string::String, value: u64, width: usize) {
     use std::string::ToString;
-    let oid = expect_tag_and_get_value(input, Tag::OID)?;
 
-    oid.read_all(Error::BadDER, |data| {
-        let mut oid_string = std::string::String::new();
-        let mut stack = std::collections::VecDeque::new();
+    let digits = value.to_string();
+    for _ in digits.len()..width {
+        out.push('0');
+    }
+    out.push_str(&digits);
+}
+
+/// Renders the `time::Time` produced by `time_choice`/`time_choice_lenient`
+/// back to a string according to a `time`-crate-style format description
+/// (e.g. `"[year]-[month]-[day]T[hour]:[minute]:[second]Z"` for RFC 3339).
+#[cfg(feature = "std")]
+pub fn format_time(t: &time::Time, desc: &str) -> Result<std::string::String, Error> {
+    use std::string::String;
+
+    let (year, month, day, hour, minute, second) = calendar::ymdhms_from_time(t)?;
+    let items = parse_format_description(desc)?;
+
+    let mut out = String::new();
+    for item in &items {
+        match *item {
+            FormatItem::Literal(ref bytes) => {
+                let text = std::str::from_utf8(bytes).map_err(|_| Error::BadDER)?;
+                out.push_str(text);
+            },
+            FormatItem::Component(Component::Year) => push_zero_padded(&mut out, year, 4),
+            FormatItem::Component(Component::Month) => push_zero_padded(&mut out, month, 2),
+            FormatItem::Component(Component::Day) => push_zero_padded(&mut out, day, 2),
+            FormatItem::Component(Component::Hour) => push_zero_padded(&mut out, hour, 2),
+            FormatItem::Component(Component::Minute) => push_zero_padded(&mut out, minute, 2),
+            FormatItem::Component(Component::Second) => push_zero_padded(&mut out, second, 2),
+        }
+    }
+
+    Ok(out)
+}
 
-        let first = data.read_byte().map_err(|_| Error::BadDER)?;
-        oid_string.push_str(&(first/40).to_string());
-        oid_string.push('.');
-        oid_string.push_str(&(first%40).to_string());
+/// A parsed ASN.1 `OBJECT IDENTIFIER`, holding the DER-encoded content
+/// octets. Arcs are decoded on demand rather than eagerly, so comparing an
+/// `Oid` against a known value (e.g. the byte array produced by the `oid!`
+/// macro) costs nothing beyond a slice comparison.
+#[derive(Debug, Clone, Copy)]
+pub struct Oid<'a>(untrusted::Input<'a>);
+
+// Walks every base-128-encoded subidentifier in an OID's content octets,
+// accumulating across continuation bytes (`byte & 0x80 != 0`) regardless of
+// which subidentifier they belong to -- including the first, which is not
+// guaranteed to fit in one byte (e.g. `2.999.3` encodes its first
+// subidentifier, 2*40+999=1079, as two bytes). `f` is called once per fully
+// decoded subidentifier. Rejects `u64` overflow and a final subidentifier
+// whose last byte still has the continuation bit set (including an input
+// that is empty or ends immediately after a continuation byte).
+fn for_each_subidentifier<F>(bytes: &[u8], mut f: F) -> Result<(), Error>
+                             where F: FnMut(u64) -> Result<(), Error> {
+    if bytes.is_empty() {
+        return Err(Error::BadDER);
+    }
+
+    let mut acc: u64 = 0;
+    let mut terminated = false;
+    for &byte in bytes {
+        acc = acc.checked_mul(128)
+                 .and_then(|acc| acc.checked_add((byte & 0x7f) as u64))
+                 .ok_or(Error::BadDER)?;
+        terminated = byte & 0x80 == 0;
+        if terminated {
+            f(acc)?;
+            acc = 0;
+        }
+    }
+
+    if !terminated {
+        return Err(Error::BadDER);
+    }
+    Ok(())
+}
 
-        while let Ok(value) = data.read_byte() {
-            if value >= 128 {
-                stack.push_front(value);
+impl<'a> Oid<'a> {
+    pub fn parse(input: &mut untrusted::Reader<'a>) -> Result<Oid<'a>, Error> {
+        let value = expect_tag_and_get_value(input, Tag::OID)?;
+        for_each_subidentifier(value.as_slice_less_safe(), |_| Ok(()))?;
+        Ok(Oid(value))
+    }
+}
+
+impl<'a> PartialEq<[u8]> for Oid<'a> {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.0.as_slice_less_safe() == other
+    }
+}
+
+impl<'a> core::fmt::Display for Oid<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let mut is_first = true;
+        for_each_subidentifier(self.0.as_slice_less_safe(), |value| {
+            if is_first {
+                is_first = false;
+                // The first subidentifier encodes the first two arcs as
+                // `(40 * arc_1) + arc_2`; clamp `arc_1` to 2 and use
+                // `value - 80` for `arc_2` once `value >= 80`, since the
+                // second arc may exceed 39 when `arc_1 == 2`.
+                let (arc_1, arc_2) = if value >= 80 {
+                    (2u64, value - 80)
+                } else {
+                    (value / 40, value % 40)
+                };
+                write!(f, "{}.{}", arc_1, arc_2).map_err(|_| Error::BadDER)
             } else {
-                oid_string.push('.');
-                let mut subtotal = value as u64;
-                let mut iteration = 0;
-                while !stack.is_empty() {
-                    iteration = iteration + 1;
-                    let prev_value = stack.pop_front().ok_or_else(|| Error::BadDER)?;
-                    subtotal = subtotal + ((prev_value - 128) as u64) * 128_u64.pow(iteration);
-                }
-                oid_string.push_str(&subtotal.to_string());
+                write!(f, ".{}", value).map_err(|_| Error::BadDER)
             }
-        }
-        Ok(oid_string)
-    })
+        }).map_err(|_| core::fmt::Error)
+    }
 }
 
 ///
 #[cfg(feature = "std")]
+pub fn parse_oid<'a>(input: &mut untrusted::Reader<'a>) -> Result<std::string::String, Error> {
+    use std::string::ToString;
+    Oid::parse(input).map(|oid| oid.to_string())
+}
+
+// ASN.1 string tags relevant to `DirectoryString` (X.520) and the IA5String
+// variants used elsewhere in PKIX names.
+const PRINTABLE_STRING: u8 = 0x13;
+const TELETEX_STRING: u8 = 0x14;
+const IA5_STRING: u8 = 0x16;
+const UTF8_STRING: u8 = 0x0c;
+const BMP_STRING: u8 = 0x1e;
+
+#[cfg(feature = "std")]
+fn is_printable_string_char(c: u8) -> bool {
+    match c {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => true,
+        b' ' | b'\'' | b'(' | b')' | b'+' | b',' | b'-' | b'.' | b'/' |
+        b':' | b'=' | b'?' => true,
+        _ => false,
+    }
+}
+
+/// Decodes an X.520 `DirectoryString`, handling all of `PrintableString`,
+/// `UTF8String`, `IA5String`, `TeletexString`, and `BMPString`.
+#[cfg(feature = "std")]
 pub fn parse_directory_string<'a>(input: &mut untrusted::Reader<'a>) -> Result<std::string::String, Error> {
     use std::vec::Vec;
     use std::string::String;
     use core::iter::FromIterator;
+    use std::char;
 
-    // Expect tag for PrintableString
-    // TODO: check for string tag
-    let (_, printable_string) =
-        read_tag_and_get_value(input).map_err(|_| Error::BadDER)?;
+    let (tag, value) = read_tag_and_get_value(input).map_err(|_| Error::BadDER)?;
+    let bytes = Vec::from_iter(value.iter().cloned());
 
-    let value = Vec::from_iter(printable_string.iter().cloned());
-    let value = String::from_utf8(value).map_err(|_| Error::BadDER);
-
-    value
+    match tag {
+        PRINTABLE_STRING => {
+            if !bytes.iter().all(|&b| is_printable_string_char(b)) {
+                return Err(Error::BadDER);
+            }
+            String::from_utf8(bytes).map_err(|_| Error::BadDER)
+        },
+        UTF8_STRING => String::from_utf8(bytes).map_err(|_| Error::BadDER),
+        IA5_STRING => {
+            if !bytes.iter().all(u8::is_ascii) {
+                return Err(Error::BadDER);
+            }
+            String::from_utf8(bytes).map_err(|_| Error::BadDER)
+        },
+        TELETEX_STRING => {
+            // TeletexString is treated as latin-1, as is common practice;
+            // every latin-1 byte value maps directly to the Unicode code
+            // point of the same number.
+            Ok(bytes.into_iter().map(|b| b as char).collect())
+        },
+        BMP_STRING => {
+            if bytes.len() % 2 != 0 {
+                return Err(Error::BadDER);
+            }
+            let units = bytes.chunks(2).map(|pair| ((pair[0] as u16) << 8) | (pair[1] as u16));
+            char::decode_utf16(units).collect::<Result<String, _>>()
+                                     .map_err(|_| Error::BadDER)
+        },
+        _ => Err(Error::BadDER),
+    }
 }
 
+// One RDN (`RelativeDistinguishedName`, a `SET OF AttributeTypeAndValue`),
+// preserving every attribute it carries and the order they appear in.
+#[cfg(feature = "std")]
+pub type Rdn = std::vec::Vec<(std::string::String, std::string::String)>;
+
 #[cfg(feature = "std")]
 #[derive(Debug)]
 pub struct Name {
-    common_name: Option<std::string::String>,
-    country_name: Option<std::string::String>,
-    locality_name: Option<std::string::String>,
-    state_or_province_name: Option<std::string::String>,
-    organization_name: Option<std::string::String>,
-    organizational_unit_name: Option<std::string::String>,
-    extra: Option<std::string::String>,
+    rdns: std::vec::Vec<Rdn>,
 }
 
-///
+// Escapes a `DirectoryString` value for use in an RFC 4514 string
+// representation: the characters `,+"\<>;` are escaped wherever they
+// appear, and a leading or trailing space is escaped in place.
 #[cfg(feature = "std")]
-pub fn parse_name<'a>(input: &mut untrusted::Reader<'a>) -> Result<Name, Error> {
+fn escape_rfc4514_value(value: &str) -> std::string::String {
     use std::string::String;
-    use std::string::ToString;
 
-    // read one name component
-    fn parse_one_name<'a>(input: &mut untrusted::Reader<'a>) -> Result<(String, String), Error> {
-        // We expect a Set here
-        if input.peek(0x31) {
-            let (_, set_inner) =
-                read_tag_and_get_value(input).map_err(|_| Error::BadDER)?;
+    let chars: std::vec::Vec<char> = value.chars().collect();
+    let last = chars.len().saturating_sub(1);
+    let mut escaped = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        let needs_escape = match c {
+            ',' | '+' | '"' | '\\' | '<' | '>' | ';' => true,
+            ' ' if i == 0 || i == last => true,
+            _ => false,
+        };
+        if needs_escape {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
 
-            // read the sequence bytes
-            let mut set_data = untrusted::Reader::new(set_inner);
-            let seq = expect_tag_and_get_value(&mut set_data, Tag::Sequence)?;
+#[cfg(feature = "std")]
+fn rfc4514_short_name(oid: &str) -> Option<&'static str> {
+    match oid {
+        "2.5.4.3" => Some("CN"),
+        "2.5.4.6" => Some("C"),
+        "2.5.4.7" => Some("L"),
+        "2.5.4.8" => Some("ST"),
+        "2.5.4.10" => Some("O"),
+        "2.5.4.11" => Some("OU"),
+        _ => None,
+    }
+}
 
-            seq.read_all(Error::BadDER, |reader| {
-                // Read attribute type and value
-                let oid = parse_oid(reader)?;
-                let name = parse_directory_string(reader)?;
+#[cfg(feature = "std")]
+impl Name {
+    /// Renders this `Name` as an RFC 4514 string, e.g.
+    /// `"CN=example.com,O=Example Co,C=US"`. RDNs are listed most-specific
+    /// first (the reverse of their encoding order, per RFC 4514 section 2);
+    /// multi-valued RDNs are joined with `+`. Attributes with a well-known
+    /// short name (`CN`, `O`, `OU`, `C`, `L`, `ST`) use it; all others fall
+    /// back to dotted-OID form.
+    pub fn to_rfc4514_string(&self) -> std::string::String {
+        let rdn_strings: std::vec::Vec<std::string::String> = self.rdns.iter().rev().map(|rdn| {
+            let attrs: std::vec::Vec<std::string::String> = rdn.iter().map(|&(ref oid, ref value)| {
+                let name = rfc4514_short_name(oid).map(std::string::String::from)
+                                                   .unwrap_or_else(|| oid.clone());
+                let mut attr = name;
+                attr.push('=');
+                attr.push_str(&escape_rfc4514_value(value));
+                attr
+            }).collect();
+            attrs.join("+")
+        }).collect();
+
+        rdn_strings.join(",")
+    }
+}
 
-                Ok((oid, name))
-            })
+// Reads one RDN: a `SET OF AttributeTypeAndValue`, preserving every
+// attribute in the set rather than assuming there is exactly one.
+#[cfg(feature = "std")]
+fn parse_rdn<'a>(input: &mut untrusted::Reader<'a>) -> Result<Rdn, Error> {
+    use std::vec::Vec;
 
-        } else {
-            Err(Error::BadDER)
+    // We expect a Set here
+    if !input.peek(0x31) {
+        return Err(Error::BadDER);
+    }
+    let (_, set_inner) = read_tag_and_get_value(input).map_err(|_| Error::BadDER)?;
+    let mut set_data = untrusted::Reader::new(set_inner);
+
+    let mut attributes = Vec::new();
+    loop {
+        let seq = expect_tag_and_get_value(&mut set_data, Tag::Sequence)?;
+        let attribute = seq.read_all(Error::BadDER, |reader| {
+            // Read attribute type and value
+            let oid = parse_oid(reader)?;
+            let name = parse_directory_string(reader)?;
+
+            Ok((oid, name))
+        })?;
+        attributes.push(attribute);
+
+        if set_data.at_end() {
+            break;
         }
     }
 
+    Ok(attributes)
+}
+
+///
+#[cfg(feature = "std")]
+pub fn parse_name<'a>(input: &mut untrusted::Reader<'a>) -> Result<Name, Error> {
+    use std::vec::Vec;
+
     // Build up the Name by reading RDNs until there is nothing left
-    let mut full_name = Name {
-        common_name: None,
-        country_name: None,
-        locality_name: None,
-        state_or_province_name: None,
-        organization_name: None,
-        organizational_unit_name: None,
-        extra: None,
-    };
-
-    while let Ok((id, name)) = parse_one_name(input) {
-        match id.as_str() {
-            "2.5.4.3" => { full_name.common_name = Some(name); },
-            "2.5.4.6" => { full_name.country_name = Some(name); },
-            "2.5.4.7" => { full_name.locality_name = Some(name); },
-            "2.5.4.8" => { full_name.state_or_province_name = Some(name); },
-            "2.5.4.10" => { full_name.organization_name = Some(name); },
-            "2.5.4.11" => { full_name.organizational_unit_name = Some(name); },
-            other => { full_name.extra = Some(other.to_string()); }
-        }
+    let mut rdns = Vec::new();
+    while let Ok(rdn) = parse_rdn(input) {
+        rdns.push(rdn);
     }
 
-    Ok(full_name)
+    Ok(Name { rdns })
+}
+
+// The `GeneralName` CHOICE, restricted to the variants `parse_alt_name`
+// knows how to decode. Each variant carries the context tag given in its
+// comment, per RFC 5280 4.2.1.6; the tag is `IMPLICIT` for every variant
+// except `DirectoryName`, whose tag is `EXPLICIT` (see `parse_alt_name`).
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum GeneralName {
+    Rfc822Name(std::string::String), // [1]
+    DnsName(std::string::String), // [2]
+    DirectoryName(Name), // [4]
+    UniformResourceIdentifier(std::string::String), // [6]
+    IpAddress(std::string::String), // [7]
+}
+
+#[cfg(feature = "std")]
+fn read_ia5_string<'a>(value: untrusted::Input<'a>) -> Result<std::string::String, Error> {
+    use std::string::String;
+    use std::vec::Vec;
+    use core::iter::FromIterator;
+
+    let bytes = Vec::from_iter(value.iter().cloned());
+    if !bytes.iter().all(u8::is_ascii) {
+        return Err(Error::BadDER);
+    }
+    String::from_utf8(bytes).map_err(|_| Error::BadDER)
+}
+
+#[cfg(feature = "std")]
+fn push_hex_byte(out: &mut std::string::String, byte: u8) {
+    const HEX_DIGITS: &'static [u8; 16] = b"0123456789abcdef";
+    out.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+    out.push(HEX_DIGITS[(byte & 0xf) as usize] as char);
 }
 
-///
 #[cfg(feature = "std")]
-pub fn parse_alt_name<'a>(input: &mut untrusted::Reader<'a>) -> Result<std::vec::Vec<std::string::String>, Error> {
+fn format_ip_address<'a>(value: untrusted::Input<'a>) -> Result<std::string::String, Error> {
     use std::string::String;
+    use std::string::ToString;
     use std::vec::Vec;
     use core::iter::FromIterator;
 
+    let bytes = Vec::from_iter(value.iter().cloned());
+    match bytes.len() {
+        4 => {
+            let mut out = String::new();
+            for (i, &octet) in bytes.iter().enumerate() {
+                if i > 0 {
+                    out.push('.');
+                }
+                out.push_str(&octet.to_string());
+            }
+            Ok(out)
+        },
+        16 => {
+            let mut out = String::new();
+            for (i, pair) in bytes.chunks(2).enumerate() {
+                if i > 0 {
+                    out.push(':');
+                }
+                push_hex_byte(&mut out, pair[0]);
+                push_hex_byte(&mut out, pair[1]);
+            }
+            Ok(out)
+        },
+        _ => Err(Error::BadDER),
+    }
+}
+
+///
+#[cfg(feature = "std")]
+pub fn parse_alt_name<'a>(input: &mut untrusted::Reader<'a>) -> Result<std::vec::Vec<GeneralName>, Error> {
+    use std::vec::Vec;
+
     let mut alt_names = Vec::new();
-    while let Ok((_, name)) = read_tag_and_get_value(input).map_err(|_| Error::BadDER) {
-        let value = Vec::from_iter(name.iter().cloned());
-        let string_name = String::from_utf8(value).map_err(|_| Error::BadDER);
-        let string_name = match string_name {
-            Ok(name) => name,
-            Err(e) => return Err(e)
+    while let Ok((tag, value)) = read_tag_and_get_value(input) {
+        let general_name = match tag & 0x1f {
+            1 => GeneralName::Rfc822Name(read_ia5_string(value)?),
+            2 => GeneralName::DnsName(read_ia5_string(value)?),
+            4 => {
+                // `Name` is a CHOICE (`Name ::= CHOICE { rdnSequence
+                // RDNSequence }`), so per X.680's exception for the
+                // IMPLICIT tags default, `directoryName` is EXPLICITLY
+                // tagged: there's a `SEQUENCE` tag+length wrapping the
+                // RDNs inside the `[4]` content that must be stripped
+                // first.
+                let mut name_reader = untrusted::Reader::new(value);
+                let rdns = expect_tag_and_get_value(&mut name_reader, Tag::Sequence)?;
+                let mut rdns_reader = untrusted::Reader::new(rdns);
+                GeneralName::DirectoryName(parse_name(&mut rdns_reader)?)
+            },
+            6 => GeneralName::UniformResourceIdentifier(read_ia5_string(value)?),
+            7 => GeneralName::IpAddress(format_ip_address(value)?),
+            _ => return Err(Error::BadDER),
         };
 
-        alt_names.push(string_name);
+        alt_names.push(general_name);
     }
 
     Ok(alt_names)
@@ -313,3 +718,244 @@ macro_rules! oid {
         [(40 * $first) + $second, $( $tail ),*]
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reader_for<'a>(bytes: &'a [u8]) -> untrusted::Reader<'a> {
+        untrusted::Reader::new(untrusted::Input::from(bytes))
+    }
+
+    #[test]
+    fn time_choice_accepts_strict_generalized_time() {
+        let bytes = b"\x18\x0f20230615123045Z";
+        let mut reader = reader_for(bytes);
+        assert!(time_choice(&mut reader).is_ok());
+    }
+
+    #[test]
+    fn time_choice_rejects_zone_offset() {
+        let bytes = b"\x18\x1320230615123045+0100";
+        let mut reader = reader_for(bytes);
+        assert!(time_choice(&mut reader).is_err());
+    }
+
+    #[test]
+    fn time_choice_lenient_normalizes_zone_offset() {
+        let with_offset = b"\x18\x1320230615133045+0100";
+        let mut reader = reader_for(with_offset);
+        let offset_time = time_choice_lenient(&mut reader).unwrap();
+
+        let utc = b"\x18\x0f20230615123045Z";
+        let mut reader = reader_for(utc);
+        let utc_time = time_choice(&mut reader).unwrap();
+
+        assert!(offset_time == utc_time);
+    }
+
+    #[test]
+    fn time_choice_lenient_skips_fractional_seconds() {
+        let bytes = b"\x18\x1120230615123045.5Z";
+        let mut reader = reader_for(bytes);
+        let fractional = time_choice_lenient(&mut reader).unwrap();
+
+        let bytes = b"\x18\x0f20230615123045Z";
+        let mut reader = reader_for(bytes);
+        let whole = time_choice(&mut reader).unwrap();
+
+        assert!(fractional == whole);
+    }
+
+    #[test]
+    fn time_choice_lenient_offset_crosses_day_month_year_boundary() {
+        let with_offset = b"\x18\x1320230101000000+0100";
+        let mut reader = reader_for(with_offset);
+        let offset_time = time_choice_lenient(&mut reader).unwrap();
+
+        let rendered =
+            format_time(&offset_time, "[year]-[month]-[day]T[hour]:[minute]:[second]Z").unwrap();
+        assert_eq!(rendered, "2022-12-31T23:00:00Z");
+    }
+
+    #[test]
+    fn time_choice_lenient_offset_borrows_across_leap_day() {
+        let with_offset = b"\x18\x1320240301000000+0100";
+        let mut reader = reader_for(with_offset);
+        let offset_time = time_choice_lenient(&mut reader).unwrap();
+
+        let rendered =
+            format_time(&offset_time, "[year]-[month]-[day]T[hour]:[minute]:[second]Z").unwrap();
+        assert_eq!(rendered, "2024-02-29T23:00:00Z");
+    }
+
+    #[test]
+    fn time_choice_lenient_rejects_offset_hour_out_of_range() {
+        let bytes = b"\x18\x1320230615123045+2400";
+        let mut reader = reader_for(bytes);
+        assert!(time_choice_lenient(&mut reader).is_err());
+    }
+
+    #[test]
+    fn time_choice_lenient_rejects_offset_minute_out_of_range() {
+        let bytes = b"\x18\x1320230615123045+0060";
+        let mut reader = reader_for(bytes);
+        assert!(time_choice_lenient(&mut reader).is_err());
+    }
+
+    #[test]
+    fn time_choice_lenient_rejects_offset_underflowing_epoch() {
+        let bytes = b"\x18\x1319700101000000+0100";
+        let mut reader = reader_for(bytes);
+        assert!(time_choice_lenient(&mut reader).is_err());
+    }
+
+    #[test]
+    fn format_time_renders_rfc3339() {
+        let bytes = b"\x18\x0f20230615123045Z";
+        let mut reader = reader_for(bytes);
+        let t = time_choice(&mut reader).unwrap();
+
+        let rendered = format_time(&t, "[year]-[month]-[day]T[hour]:[minute]:[second]Z").unwrap();
+        assert_eq!(rendered, "2023-06-15T12:30:45Z");
+    }
+
+    #[test]
+    fn format_time_rejects_unknown_component() {
+        let bytes = b"\x18\x0f20230615123045Z";
+        let mut reader = reader_for(bytes);
+        let t = time_choice(&mut reader).unwrap();
+
+        assert!(format_time(&t, "[bogus]").is_err());
+    }
+
+    #[test]
+    fn parse_alt_name_decodes_directory_name_and_ip_address() {
+        // [4] { SEQUENCE { SET { SEQUENCE { OID 2.5.4.3, PrintableString "x" } } } },
+        // followed by [7] (iPAddress) 192.0.2.1.
+        let bytes: &[u8] = &[
+            0xA4, 0x0e, 0x30, 0x0c, 0x31, 0x0a, 0x30, 0x08,
+            0x06, 0x03, 0x55, 0x04, 0x03, 0x13, 0x01, b'x',
+            0x87, 0x04, 192, 0, 2, 1,
+        ];
+        let mut reader = reader_for(bytes);
+        let names = parse_alt_name(&mut reader).unwrap();
+        assert_eq!(names.len(), 2);
+
+        match &names[0] {
+            GeneralName::DirectoryName(name) => {
+                assert_eq!(name.to_rfc4514_string(), "CN=x");
+            },
+            other => panic!("expected DirectoryName, got {:?}", other),
+        }
+        match &names[1] {
+            GeneralName::IpAddress(ip) => assert_eq!(ip, "192.0.2.1"),
+            other => panic!("expected IpAddress, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_directory_string_decodes_every_tag() {
+        let printable: &[u8] = &[0x13, 0x02, b'h', b'i'];
+        assert_eq!(parse_directory_string(&mut reader_for(printable)).unwrap(), "hi");
+
+        let printable_invalid: &[u8] = &[0x13, 0x01, b'_'];
+        assert!(parse_directory_string(&mut reader_for(printable_invalid)).is_err());
+
+        let utf8: &[u8] = &[0x0c, 0x02, b'h', b'i'];
+        assert_eq!(parse_directory_string(&mut reader_for(utf8)).unwrap(), "hi");
+
+        let ia5: &[u8] = &[0x16, 0x02, b'h', b'i'];
+        assert_eq!(parse_directory_string(&mut reader_for(ia5)).unwrap(), "hi");
+
+        // TeletexString is decoded as latin-1; 0xe9 is LATIN SMALL LETTER E
+        // WITH ACUTE in both latin-1 and Unicode.
+        let teletex: &[u8] = &[0x14, 0x01, 0xe9];
+        assert_eq!(parse_directory_string(&mut reader_for(teletex)).unwrap(), "\u{e9}");
+
+        // BMPString is UTF-16BE; 0x0041 is 'A'.
+        let bmp: &[u8] = &[0x1e, 0x02, 0x00, 0x41];
+        assert_eq!(parse_directory_string(&mut reader_for(bmp)).unwrap(), "A");
+
+        let unknown_tag: &[u8] = &[0x04, 0x01, b'x'];
+        assert!(parse_directory_string(&mut reader_for(unknown_tag)).is_err());
+    }
+
+    // Builds a DER `AttributeTypeAndValue` SEQUENCE from an OID's content
+    // octets, a `DirectoryString` tag, and the string's content octets.
+    fn atv_seq(oid_bytes: &[u8], string_tag: u8, value: &[u8]) -> std::vec::Vec<u8> {
+        let mut content = std::vec::Vec::new();
+        content.push(0x06);
+        content.push(oid_bytes.len() as u8);
+        content.extend_from_slice(oid_bytes);
+        content.push(string_tag);
+        content.push(value.len() as u8);
+        content.extend_from_slice(value);
+
+        let mut seq = std::vec![0x30, content.len() as u8];
+        seq.extend_from_slice(&content);
+        seq
+    }
+
+    // Builds a DER RDN (`SET OF AttributeTypeAndValue`) from the given
+    // already-encoded `AttributeTypeAndValue` sequences.
+    fn rdn_set(atvs: &[std::vec::Vec<u8>]) -> std::vec::Vec<u8> {
+        let mut content = std::vec::Vec::new();
+        for atv in atvs {
+            content.extend_from_slice(atv);
+        }
+
+        let mut set = std::vec![0x31, content.len() as u8];
+        set.extend_from_slice(&content);
+        set
+    }
+
+    #[test]
+    fn parse_name_preserves_multi_valued_rdns_in_rfc4514_order() {
+        let o = atv_seq(&[0x55, 0x04, 0x0a], PRINTABLE_STRING, b"Example");
+        let cn = atv_seq(&[0x55, 0x04, 0x03], PRINTABLE_STRING, b"test");
+        let ou = atv_seq(&[0x55, 0x04, 0x0b], PRINTABLE_STRING, b"Eng");
+
+        let mut bytes = rdn_set(&[o]);
+        bytes.extend_from_slice(&rdn_set(&[cn, ou]));
+
+        let mut reader = reader_for(&bytes);
+        let name = parse_name(&mut reader).unwrap();
+
+        // RDNs render most-specific first (reverse of encoding order);
+        // attributes within a multi-valued RDN keep their encoded order.
+        assert_eq!(name.to_rfc4514_string(), "CN=test+OU=Eng,O=Example");
+    }
+
+    #[test]
+    fn to_rfc4514_string_escapes_special_characters() {
+        let cn = atv_seq(&[0x55, 0x04, 0x03], PRINTABLE_STRING, b"a,b");
+        let bytes = rdn_set(&[cn]);
+
+        let mut reader = reader_for(&bytes);
+        let name = parse_name(&mut reader).unwrap();
+
+        assert_eq!(name.to_rfc4514_string(), "CN=a\\,b");
+    }
+
+    #[test]
+    fn oid_decodes_multi_byte_first_subidentifier() {
+        use std::string::ToString;
+
+        // 2.999.3: the first subidentifier, 2*40+999=1079, does not fit in
+        // one base-128 byte.
+        let bytes: &[u8] = &[0x06, 0x03, 0x88, 0x37, 0x03];
+        let mut reader = reader_for(bytes);
+        let oid = Oid::parse(&mut reader).unwrap();
+
+        assert_eq!(oid.to_string(), "2.999.3");
+        assert!(oid == bytes[2..]);
+    }
+
+    #[test]
+    fn oid_rejects_unterminated_subidentifier() {
+        let bytes: &[u8] = &[0x06, 0x01, 0x88];
+        let mut reader = reader_for(bytes);
+        assert!(Oid::parse(&mut reader).is_err());
+    }
+}
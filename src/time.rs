@@ -0,0 +1,33 @@
+// Copyright 2015 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+/// A UTC point in time, used for certificate validity comparisons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Time(u64);
+
+impl Time {
+    /// Constructs a `Time` from the number of seconds since the Unix epoch
+    /// (1970-01-01T00:00:00Z).
+    pub fn from_seconds_since_unix_epoch(secs: u64) -> Time {
+        Time(secs)
+    }
+
+    /// The number of seconds since the Unix epoch this `Time` represents.
+    /// `pub(crate)` since it is only needed by `calendar` (to recover civil
+    /// fields for `der::format_time`) and `der` (to normalize a zone
+    /// offset onto an already-computed `Time`).
+    pub(crate) fn as_seconds_since_unix_epoch(&self) -> u64 {
+        self.0
+    }
+}